@@ -1,7 +1,7 @@
-use std::{cell::RefCell, fs::{self, File}, io::BufReader, path::Path, rc::Rc};
+use std::{cell::RefCell, fs::{self, File}, io::{BufReader, Read, Write}, path::{Path, PathBuf}, rc::Rc};
 
 use exif::{In, Reader, Tag, Value};
-use fltk::{app::{self, redraw},
+use fltk::{app::{self},
      button::Button,
      dialog::{FileDialog, FileDialogType}, 
      enums::ColorDepth, 
@@ -11,71 +11,118 @@ use fltk::{app::{self, redraw},
      prelude::*, 
      window::Window};
 use image::GenericImageView;
+use little_exif::{exif_tag::ExifTag, metadata::Metadata, rational::uR64};
+
+
+
+/// A validated GPS fix read from an image's EXIF block.
+///
+/// Built by [`GpsInfoBuilder`], which walks the EXIF fields once and hands
+/// callers a single typed value instead of re-parsing the container for each
+/// component. `altitude` and `timestamp` are optional; latitude and longitude
+/// are always present and already normalized.
+#[derive(Debug, Clone)]
+struct GpsInfo {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+    timestamp: Option<String>,
+}
 
-
-
-fn get_gps_rational(exif: &exif::Exif, tag: Tag) -> Option<Vec<exif::Rational>> {
-    if let Some(field) = exif.get_field(tag, In::PRIMARY) {
-        if let Value::Rational(rationals) = &field.value {
-            return Some(rationals.to_vec());
-        }
-    }
-    None
+/// Collects the raw GPS components as the EXIF fields are iterated, then
+/// validates them in [`build`](GpsInfoBuilder::build).
+#[derive(Default)]
+struct GpsInfoBuilder {
+    latitude: Option<Vec<exif::Rational>>,
+    latitude_ref: Option<String>,
+    longitude: Option<Vec<exif::Rational>>,
+    longitude_ref: Option<String>,
+    altitude: Option<Vec<exif::Rational>>,
+    altitude_ref: u8, // 0 = above sea level, 1 = below
+    timestamp: Option<Vec<exif::Rational>>,
+    date: Option<String>,
 }
 
-fn get_gps_ref(exif: &exif::Exif, tag: Tag) -> Option<String> {
-    if let Some(field) = exif.get_field(tag, In::PRIMARY) {
-        if let Value::Ascii(chars) = &field.value {
-            if let Ok(s) = std::str::from_utf8(&chars[0]) {
-                return Some(s.to_string());
+impl GpsInfoBuilder {
+    /// Iterate the primary EXIF fields once, filling each GPS component as it
+    /// is encountered.
+    fn from_exif(exif: &exif::Exif) -> Self {
+        let mut builder = Self::default();
+        for field in exif.fields() {
+            if field.ifd_num != In::PRIMARY {
+                continue;
+            }
+            match field.tag {
+                Tag::GPSLatitude => builder.latitude = rationals(&field.value),
+                Tag::GPSLatitudeRef => builder.latitude_ref = ascii(&field.value),
+                Tag::GPSLongitude => builder.longitude = rationals(&field.value),
+                Tag::GPSLongitudeRef => builder.longitude_ref = ascii(&field.value),
+                Tag::GPSAltitude => builder.altitude = rationals(&field.value),
+                Tag::GPSAltitudeRef => {
+                    if let Value::Byte(bytes) = &field.value {
+                        builder.altitude_ref = bytes.first().copied().unwrap_or(0);
+                    }
+                }
+                Tag::GPSTimeStamp => builder.timestamp = rationals(&field.value),
+                Tag::GPSDateStamp => builder.date = ascii(&field.value),
+                _ => {}
             }
         }
+        builder
     }
-    None
-}
 
-fn get_gps_altitude(exif: &exif::Exif) -> Option<f64> {
-    let altitude = get_gps_rational(exif, Tag::GPSAltitude)?;
-    if altitude.is_empty() {
-        return None;
+    /// Decode the altitude component, independent of the lat/lon fix. Present
+    /// whenever the EXIF block carries a `GPSAltitude` rational.
+    fn altitude_value(&self) -> Option<f64> {
+        self.altitude.as_ref().filter(|a| !a.is_empty()).map(|a| {
+            let altitude = a[0].to_f64();
+            if self.altitude_ref != 0 { -altitude } else { altitude }
+        })
     }
 
-    let alt_ref = exif
-        .get_field(Tag::GPSAltitudeRef, In::PRIMARY)
-        .and_then(|field| {
-            if let Value::Byte(bytes) = &field.value {
-                Some(bytes[0] != 0) // 0 = above sea level, 1 = below
-            } else {
-                None
+    /// Decode the timestamp component, independent of the lat/lon fix. Present
+    /// whenever the EXIF block carries a `GPSTimeStamp` triple.
+    fn timestamp_value(&self) -> Option<String> {
+        self.timestamp.as_ref().filter(|t| t.len() == 3).map(|t| {
+            let hour = t[0].to_f64() as u32;
+            let minute = t[1].to_f64() as u32;
+            let second = t[2].to_f64() as u32;
+            match &self.date {
+                Some(date) => format!("{} {:02}:{:02}:{:02}", date, hour, minute, second),
+                None => format!("{:02}:{:02}:{:02}", hour, minute, second),
             }
         })
-        .unwrap_or(false);
+    }
 
-    let altitude = altitude[0].to_f64();
-    Some(if alt_ref { -altitude } else { altitude })
-}
+    /// Require all four of latitude/latitude-ref/longitude/longitude-ref and a
+    /// coordinate that survives [`normalize_gps`]; otherwise `None`.
+    fn build(self) -> Option<GpsInfo> {
+        let altitude = self.altitude_value();
+        let timestamp = self.timestamp_value();
 
-fn get_gps_timestamp(exif: &exif::Exif) -> Option<String> {
-    let time = get_gps_rational(exif, Tag::GPSTimeStamp)?;
-    if time.len() != 3 {
-        return None;
-    }
+        let latitude = convert_to_decimal_degree(self.latitude?, self.latitude_ref?);
+        let longitude = convert_to_decimal_degree(self.longitude?, self.longitude_ref?);
+        let (latitude, longitude) = normalize_gps(latitude, longitude)?;
 
-    let hour = time[0].to_f64() as u32;
-    let minute = time[1].to_f64() as u32;
-    let second = time[2].to_f64() as u32;
+        Some(GpsInfo { latitude, longitude, altitude, timestamp })
+    }
+}
 
-    let date = exif.get_field(Tag::GPSDateStamp, In::PRIMARY).and_then(|field| {
-        if let Value::Ascii(chars) = &field.value {
-            std::str::from_utf8(&chars[0]).ok().map(|s| s.to_string())
-        } else {
-            None
-        }
-    });
+/// Extract a rational vector from an EXIF value, if it is one.
+fn rationals(value: &Value) -> Option<Vec<exif::Rational>> {
+    if let Value::Rational(rationals) = value {
+        Some(rationals.to_vec())
+    } else {
+        None
+    }
+}
 
-    match date {
-        Some(date) => Some(format!("{} {:02}:{:02}:{:02}", date, hour, minute, second)),
-        None => Some(format!("{:02}:{:02}:{:02}", hour, minute, second)),
+/// Extract the first ASCII string from an EXIF value, if it is one.
+fn ascii(value: &Value) -> Option<String> {
+    if let Value::Ascii(chars) = value {
+        std::str::from_utf8(chars.first()?).ok().map(|s| s.to_string())
+    } else {
+        None
     }
 }
 
@@ -97,6 +144,70 @@ fn convert_to_decimal_degree(components: Vec<exif::Rational>, reference: String)
     decimal
 }
 
+/// Validate a decoded GPS coordinate before it reaches any consumer.
+///
+/// Rejects `NaN` values and the exact `(0.0, 0.0)` null-island coordinate that
+/// cameras emit when no fix was obtained, and returns `None` when either
+/// component falls outside its valid range rather than displaying garbage.
+/// Surviving values are rounded to six decimal places to drop spurious
+/// precision.
+fn normalize_gps(lat: f64, lon: f64) -> Option<(f64, f64)> {
+    if lat.is_nan() || lon.is_nan() {
+        return None;
+    }
+    if lat == 0.0 && lon == 0.0 {
+        return None;
+    }
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return None;
+    }
+
+    let round6 = |v: f64| (v * 1_000_000.0).round() / 1_000_000.0;
+    Some((round6(lat), round6(lon)))
+}
+
+/// Split a decimal degree value into EXIF degrees/minutes/seconds rationals,
+/// the inverse of [`convert_to_decimal_degree`]. Seconds keep three fractional
+/// digits via a `1000` denominator so the round-trip stays accurate.
+fn decimal_to_dms(value: f64) -> [uR64; 3] {
+    let value = value.abs();
+    let degrees = value.trunc();
+    let minutes = ((value - degrees) * 60.0).trunc();
+    let seconds = (((value - degrees) * 60.0) - minutes) * 60.0;
+
+    [
+        uR64 { nominator: degrees as u32, denominator: 1 },
+        uR64 { nominator: minutes as u32, denominator: 1 },
+        uR64 { nominator: (seconds * 1000.0).round() as u32, denominator: 1000 },
+    ]
+}
+
+/// Write GPS tags into a JPEG/TIFF that lacks them. Serializes latitude and
+/// longitude as DMS rationals with N/S and E/W reference chars, plus altitude
+/// (and its above/below-sea-level ref). The `exif` crate used elsewhere is
+/// read-only, so writing goes through `little_exif`.
+fn geotag_image(path: &Path, lat: f64, lon: f64, alt: f64) -> Result<(), String> {
+    let (lat, lon) = normalize_gps(lat, lon).ok_or_else(|| "Invalid coordinate".to_string())?;
+
+    let mut metadata = Metadata::new_from_path(path).map_err(|e| e.to_string())?;
+
+    metadata.set_tag(ExifTag::GPSLatitude(decimal_to_dms(lat).to_vec()));
+    metadata.set_tag(ExifTag::GPSLatitudeRef(
+        if lat >= 0.0 { "N" } else { "S" }.to_string(),
+    ));
+    metadata.set_tag(ExifTag::GPSLongitude(decimal_to_dms(lon).to_vec()));
+    metadata.set_tag(ExifTag::GPSLongitudeRef(
+        if lon >= 0.0 { "E" } else { "W" }.to_string(),
+    ));
+    metadata.set_tag(ExifTag::GPSAltitude(vec![uR64 {
+        nominator: (alt.abs() * 1000.0).round() as u32,
+        denominator: 1000,
+    }]));
+    metadata.set_tag(ExifTag::GPSAltitudeRef(vec![if alt < 0.0 { 1 } else { 0 }]));
+
+    metadata.write_to_file(path).map_err(|e| e.to_string())
+}
+
 fn load_any_image(path: &str) -> Option<RgbImage> {
     match image::open(path) {
         Ok(img) => {
@@ -141,35 +252,34 @@ fn get_file_info(path: &Path) -> Vec<String> {
     if let Ok(file) = File::open(path) {
         let mut reader = BufReader::new(file);
         if let Ok(exif) = Reader::new().read_from_container(&mut reader) {
-            if let (Some(lat), Some(lat_ref), Some(lon), Some(lon_ref)) = (
-                get_gps_rational(&exif, Tag::GPSLatitude),
-                get_gps_ref(&exif, Tag::GPSLatitudeRef),
-                get_gps_rational(&exif, Tag::GPSLongitude),
-                get_gps_ref(&exif, Tag::GPSLongitudeRef),
-            ) {
-                let latitude = convert_to_decimal_degree(lat, lat_ref);
-                let longitude = convert_to_decimal_degree(lon, lon_ref);
+            let builder = GpsInfoBuilder::from_exif(&exif);
 
+            // Altitude and timestamp are shown whenever present, independent of
+            // whether a valid lat/lon fix survives `build()`.
+            let altitude = builder.altitude_value();
+            let timestamp = builder.timestamp_value();
+
+            if let Some(gps) = builder.build() {
                 info.push(format!("Location: {:.6}*{}, {:.6}*{}",
-                    latitude.abs(),
-                    if latitude >= 0.0 { "N" } else { "S" },
-                    longitude.abs(),
-                    if longitude >= 0.0 { "E" } else { "W" }
+                    gps.latitude.abs(),
+                    if gps.latitude >= 0.0 { "N" } else { "S" },
+                    gps.longitude.abs(),
+                    if gps.longitude >= 0.0 { "E" } else { "W" }
                 ));
 
                 info.push(format!("Google Maps Link: https://www.google.com/maps?q={},{}",
-                    latitude, longitude
+                    gps.latitude, gps.longitude
                 ));
             }
 
-            if let Some(alt) = get_gps_altitude(&exif) {
+            if let Some(alt) = altitude {
                 info.push(format!("Altitude: {:.1} meters", alt));
             }
 
-            if let Some(time) = get_gps_timestamp(&exif) {
+            if let Some(time) = timestamp {
                 info.push(format!("GPS Timestamp: {}", time));
             }
-        } 
+        }
     }
 
     // Format and additional information
@@ -196,6 +306,199 @@ fn get_file_info(path: &Path) -> Vec<String> {
     info
 }
 
+const SUPPORTED_EXTENSIONS: [&str; 7] = ["jpg", "jpeg", "png", "gif", "bmp", "tif", "tiff"];
+
+/// Output format for the batch map export.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Kml,
+    Gpx,
+}
+
+/// Read the GPS fix from a single image, returning `(latitude, longitude,
+/// altitude, timestamp)` in decimal degrees. Returns `None` when the image
+/// has no GPS block or carries the degenerate null-island coordinate.
+fn read_photo_gps(path: &Path) -> Option<(f64, f64, f64, Option<String>)> {
+    let file = File::open(path).ok()?;
+    let mut reader = BufReader::new(file);
+    let exif = Reader::new().read_from_container(&mut reader).ok()?;
+
+    let gps = GpsInfoBuilder::from_exif(&exif).build()?;
+    Some((gps.latitude, gps.longitude, gps.altitude.unwrap_or(0.0), gps.timestamp))
+}
+
+/// Recursively collect every supported image under `dir`.
+fn collect_images(dir: &Path, out: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read directory {}: {}", dir.display(), e);
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_images(&path, out);
+        } else if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            if SUPPORTED_EXTENSIONS.iter().any(|e| ext.eq_ignore_ascii_case(e)) {
+                out.push(path);
+            }
+        }
+    }
+}
+
+/// Turn a [`GpsInfo::timestamp`] value into an ISO-8601 `xsd:dateTime` string
+/// for GPX `<time>`, or `None` when only a time-of-day is available.
+///
+/// EXIF `GPSDateStamp` is colon-separated (`"YYYY:MM:DD"`), so the date portion
+/// is rewritten with dashes before the `date T time Z` join. A bare
+/// `"HH:MM:SS"` (no `GPSDateStamp`) is not a valid `xsd:dateTime`, so it yields
+/// `None` and the caller omits the element rather than emitting garbage.
+fn to_iso8601(timestamp: &str) -> Option<String> {
+    let (date, time) = timestamp.split_once(' ')?;
+    Some(format!("{}T{}Z", date.replace(':', "-"), time))
+}
+
+/// Escape the XML metacharacters in user-derived text (e.g. file names) so it
+/// can be interpolated into a KML/GPX element body without breaking the
+/// document.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Walk `dir`, read GPS from every geotagged photo and write the resulting
+/// track/waypoints to `output` in the requested [`ExportFormat`]. Returns the
+/// number of placemarks written.
+fn export_map(dir: &Path, output: &Path, format: ExportFormat) -> std::io::Result<usize> {
+    let mut images = Vec::new();
+    collect_images(dir, &mut images);
+
+    let mut count = 0;
+    let mut body = String::new();
+    for path in &images {
+        let Some((lat, lon, alt, time)) = read_photo_gps(path) else {
+            continue;
+        };
+        let name = escape_xml(
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("photo"),
+        );
+
+        match format {
+            ExportFormat::Kml => {
+                body.push_str(&format!(
+                    "    <Placemark>\n      <name>{}</name>\n      <Point><coordinates>{},{},{}</coordinates></Point>\n    </Placemark>\n",
+                    name, lon, lat, alt
+                ));
+            }
+            ExportFormat::Gpx => {
+                let time = time
+                    .as_deref()
+                    .and_then(to_iso8601)
+                    .map(|t| format!("<time>{}</time>", t))
+                    .unwrap_or_default();
+                body.push_str(&format!(
+                    "  <wpt lat=\"{}\" lon=\"{}\"><ele>{}</ele>{}<name>{}</name></wpt>\n",
+                    lat, lon, alt, time, name
+                ));
+            }
+        }
+        count += 1;
+    }
+
+    let document = match format {
+        ExportFormat::Kml => format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n  <Document>\n{}  </Document>\n</kml>\n",
+            body
+        ),
+        ExportFormat::Gpx => format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<gpx version=\"1.1\" creator=\"Image Viewer\">\n{}</gpx>\n",
+            body
+        ),
+    };
+
+    let mut file = File::create(output)?;
+    file.write_all(document.as_bytes())?;
+    Ok(count)
+}
+
+/// Zoom level and tile source used for the in-window map thumbnail. The URL
+/// template takes `{z}`/`{x}`/`{y}` placeholders so the source can be swapped.
+const TILE_ZOOM: u32 = 13;
+const TILE_URL_TEMPLATE: &str = "https://tile.openstreetmap.org/{z}/{x}/{y}.png";
+/// Identifying User-Agent sent with tile requests; tile servers reject generic
+/// library agents.
+const TILE_USER_AGENT: &str =
+    "gps-image/0.1 (https://github.com/noxxspring/GPS-image)";
+
+/// Convert a coordinate to slippy-map tile x/y at the given zoom.
+fn lat_lon_to_tile(lat: f64, lon: f64, zoom: u32) -> (u32, u32) {
+    let n = 2f64.powi(zoom as i32);
+    let x = ((lon + 180.0) / 360.0 * n).floor() as u32;
+    let lat_rad = lat.to_radians();
+    let y = ((1.0 - (lat_rad.tan() + 1.0 / lat_rad.cos()).ln() / std::f64::consts::PI) / 2.0 * n)
+        .floor() as u32;
+    (x, y)
+}
+
+/// Draw a small red cross at the centre of a tile. The cross marks the tile's
+/// centre, not the photo's exact position inside the tile.
+fn draw_pin(img: &mut image::RgbImage) {
+    let (cx, cy) = (img.width() as i32 / 2, img.height() as i32 / 2);
+    let red = image::Rgb([220, 20, 20]);
+    for d in 0..7 {
+        for (px, py) in [(cx, cy - d), (cx, cy + d), (cx - d, cy), (cx + d, cy)] {
+            if px >= 0 && py >= 0 && (px as u32) < img.width() && (py as u32) < img.height() {
+                img.put_pixel(px as u32, py as u32, red);
+            }
+        }
+    }
+}
+
+/// Fetch the map tile containing `(lat, lon)`, drawing the pin at its centre.
+/// The tile is not re-centred on the coordinate, so the photo can lie anywhere
+/// inside it. Tiles are cached on disk keyed by z/x/y so repeat views work
+/// offline.
+fn fetch_map_tile(lat: f64, lon: f64) -> Option<RgbImage> {
+    let (x, y) = lat_lon_to_tile(lat, lon, TILE_ZOOM);
+
+    let cache_dir = std::env::temp_dir().join("gps_image_tiles");
+    let _ = fs::create_dir_all(&cache_dir);
+    let cache_path = cache_dir.join(format!("{}_{}_{}.png", TILE_ZOOM, x, y));
+
+    let bytes = match fs::read(&cache_path) {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            let url = TILE_URL_TEMPLATE
+                .replace("{z}", &TILE_ZOOM.to_string())
+                .replace("{x}", &x.to_string())
+                .replace("{y}", &y.to_string());
+            let mut buf = Vec::new();
+            ureq::get(&url)
+                // OSM and most tile servers reject generic library agents, so
+                // identify ourselves per their usage policy.
+                .set("User-Agent", TILE_USER_AGENT)
+                .call()
+                .ok()?
+                .into_reader()
+                .read_to_end(&mut buf)
+                .ok()?;
+            let _ = fs::write(&cache_path, &buf);
+            buf
+        }
+    };
+
+    let mut tile = image::load_from_memory(&bytes).ok()?.to_rgb8();
+    draw_pin(&mut tile);
+    let (w, h) = (tile.width(), tile.height());
+    RgbImage::new(&tile, w as i32, h as i32, ColorDepth::Rgb8).ok()
+}
+
 fn main() {
     let app = app::App::default();
     let mut wind = Window::new(100, 100, 800, 600, "Image Viewer");
@@ -204,6 +507,8 @@ fn main() {
     let toolbar = Group::new(0, 0, 800, 40, "");
     let mut open_btn = Button::new(10, 5, 100, 30, "Open Image");
     let mut info_btn = Button::new(120, 5, 100, 30, "Image Info");
+    let mut export_btn = Button::new(230, 5, 100, 30, "Export Map");
+    let mut geotag_btn = Button::new(340, 5, 100, 30, "Set Location");
     toolbar.end();
 
     // create frame for image display
@@ -285,6 +590,15 @@ fn main() {
                         Frame::new(0, 0, 400, 30, &info_line[..]);
                     }
                 }
+
+                // Render a static map tile with the pin drawn at centre; the
+                // clickable Maps link above remains as a fallback.
+                if let Some((lat, lon, _, _)) = read_photo_gps(path) {
+                    if let Some(tile) = fetch_map_tile(lat, lon) {
+                        let mut map_frame = Frame::new(0, 0, tile.width(), tile.height(), "");
+                        map_frame.set_image(Some(tile));
+                    }
+                }
             }
 
             pack.end();
@@ -294,6 +608,64 @@ fn main() {
         }
     });
 
+    // Export Map: pick a folder, scan it recursively and write KML/GPX.
+    export_btn.set_callback(move |_| {
+        let mut dir_dialog = FileDialog::new(FileDialogType::BrowseDir);
+        dir_dialog.show();
+        let dir = dir_dialog.filename();
+        if dir.as_os_str().is_empty() {
+            return;
+        }
+
+        let mut save_dialog = FileDialog::new(FileDialogType::BrowseSaveFile);
+        save_dialog.set_filter("Map files\t*.{kml,gpx}");
+        save_dialog.show();
+        let output = save_dialog.filename();
+        if output.as_os_str().is_empty() {
+            return;
+        }
+
+        let format = match output.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("gpx") => ExportFormat::Gpx,
+            _ => ExportFormat::Kml,
+        };
+
+        match export_map(&dir, &output, format) {
+            Ok(count) => println!("Exported {} placemarks to {}", count, output.display()),
+            Err(e) => eprintln!("Failed to export map: {}", e),
+        }
+    });
+
+    // Set Location: stamp coordinates into an image that lacks GPS data.
+    geotag_btn.set_callback(move |_| {
+        let mut dialog = FileDialog::new(FileDialogType::BrowseFile);
+        dialog.set_filter("Image files\t*.{jpg,jpeg,tif,tiff}");
+        dialog.show();
+        let path = dialog.filename();
+        if path.as_os_str().is_empty() {
+            return;
+        }
+
+        let Some(lat) = fltk::dialog::input_default("Latitude (decimal degrees):", "")
+            .and_then(|s| s.trim().parse::<f64>().ok())
+        else {
+            return;
+        };
+        let Some(lon) = fltk::dialog::input_default("Longitude (decimal degrees):", "")
+            .and_then(|s| s.trim().parse::<f64>().ok())
+        else {
+            return;
+        };
+        let alt = fltk::dialog::input_default("Altitude (meters):", "0")
+            .and_then(|s| s.trim().parse::<f64>().ok())
+            .unwrap_or(0.0);
+
+        match geotag_image(&path, lat, lon, alt) {
+            Ok(()) => println!("Geotagged {}", path.display()),
+            Err(e) => eprintln!("Failed to geotag image: {}", e),
+        }
+    });
+
     wind.end();
     wind.show();
 